@@ -0,0 +1,167 @@
+//! A* pathfinding controller, used as a strong scripted opponent and a
+//! fitness yardstick alongside the evolved [`Brain`](crate::Brain) and
+//! [`MctsBrain`](crate::mcts::MctsBrain).
+
+use crate::{Dir, Pos, Snake, cols, rows};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+const DIRECTIONS: [Dir; 4] = [Dir::Vertical(-1), Dir::Vertical(1), Dir::Horizontal(-1), Dir::Horizontal(1)];
+
+fn in_bounds(pos: Pos) -> bool {
+    pos.x >= 0 && pos.x < cols() && pos.y >= 0 && pos.y < rows()
+}
+
+fn manhattan(a: Pos, b: Pos) -> i32 {
+    (a.x - b.x).abs() + (a.y - b.y).abs()
+}
+
+/// A cell on the open set, ordered by `f = g + h` (lowest first).
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct OpenCell {
+    f: i32,
+    pos: Pos,
+}
+
+impl Ord for OpenCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so `BinaryHeap`, a max-heap, pops the lowest `f` first.
+        other.f.cmp(&self.f)
+    }
+}
+
+impl PartialOrd for OpenCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cells currently occupied by `snake`'s body, excluding the tail (which
+/// vacates on the next tick, so it's safe to path through).
+fn occupied_cells(snake: &Snake) -> HashSet<Pos> {
+    let tail = *snake.body.last().unwrap();
+    snake.body.iter().copied().filter(|&p| p != tail).collect()
+}
+
+/// Runs A* from `snake`'s head to `food` and returns the first step of the
+/// shortest path, or `None` if no path exists.
+fn find_path(snake: &Snake, food: Pos) -> Option<Dir> {
+    let start = snake.head();
+    if start == food {
+        // Food can respawn under the snake's own head right after it's
+        // eaten; there's no step to take, so just keep going straight.
+        return Some(snake.direction);
+    }
+    let occupied = occupied_cells(snake);
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<Pos, (Pos, Dir)> = HashMap::new();
+    let mut g_score: HashMap<Pos, i32> = HashMap::new();
+
+    g_score.insert(start, 0);
+    open.push(OpenCell {
+        f: manhattan(start, food),
+        pos: start,
+    });
+
+    while let Some(OpenCell { pos, .. }) = open.pop() {
+        if pos == food {
+            return Some(first_step(&came_from, start, pos));
+        }
+
+        let g = g_score[&pos];
+        for dir in DIRECTIONS {
+            let mut next = pos;
+            next += dir;
+            if !in_bounds(next) || occupied.contains(&next) {
+                continue;
+            }
+
+            let tentative_g = g + 1;
+            if tentative_g < *g_score.get(&next).unwrap_or(&i32::MAX) {
+                g_score.insert(next, tentative_g);
+                came_from.insert(next, (pos, dir));
+                open.push(OpenCell {
+                    f: tentative_g + manhattan(next, food),
+                    pos: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Walks `came_from` back from `goal` to `start` and returns the direction
+/// taken on the first step of the path.
+fn first_step(came_from: &HashMap<Pos, (Pos, Dir)>, start: Pos, goal: Pos) -> Dir {
+    let mut pos = goal;
+    loop {
+        let (prev, dir) = came_from[&pos];
+        if prev == start {
+            return dir;
+        }
+        pos = prev;
+    }
+}
+
+/// Number of cells reachable from `start` via flood fill, treating
+/// `occupied` cells and the board edges as walls.
+fn reachable_area(start: Pos, occupied: &HashSet<Pos>) -> usize {
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+    visited.insert(start);
+
+    while let Some(pos) = stack.pop() {
+        for dir in DIRECTIONS {
+            let mut next = pos;
+            next += dir;
+            if in_bounds(next) && !occupied.contains(&next) && visited.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    visited.len()
+}
+
+/// Steers the snake toward `food` via A*, falling back to the move that
+/// maximizes reachable free space (flood fill) when no path to food
+/// exists, to avoid trapping itself in its own coils.
+pub struct AStarBrain;
+
+impl AStarBrain {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn decide(&self, snake: &Snake, food: Pos) -> Dir {
+        if let Some(dir) = find_path(snake, food) {
+            return dir;
+        }
+        self.safest_move(snake)
+    }
+
+    fn safest_move(&self, snake: &Snake) -> Dir {
+        let occupied = occupied_cells(snake);
+        snake
+            .direction
+            .non_reversing_moves()
+            .into_iter()
+            .max_by_key(|dir| {
+                let mut head = snake.head();
+                head += *dir;
+                if !in_bounds(head) || occupied.contains(&head) {
+                    return 0;
+                }
+                reachable_area(head, &occupied)
+            })
+            .unwrap_or(snake.direction)
+    }
+}
+
+impl Default for AStarBrain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
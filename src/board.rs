@@ -0,0 +1,301 @@
+//! A multi-snake competitive board, generalizing the single-player `Game`
+//! into a Battlesnake-style arena where several evolved [`Brain`]s compete
+//! for the same food.
+
+use crate::{cols, pheromone_index, rows, Brain, Dir, Pos, Snake, PHEROMONE_DECAY, PHEROMONE_DEPOSIT};
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+/// A single head-to-head or multi-way match between `brains.len()` snakes.
+pub struct Board {
+    snakes: Vec<Snake>,
+    brains: Vec<Brain>,
+    alive: Vec<bool>,
+    /// The tick each snake died on, recorded in `resolve_collisions`; `None`
+    /// for snakes still alive (or that survived to the end of the match).
+    death_step: Vec<Option<usize>>,
+    foods: Vec<Pos>,
+    steps: usize,
+    rng: StdRng,
+    /// Each snake's own decaying pheromone trail, indexed the same way as
+    /// `Game::pheromones`.
+    pheromones: Vec<Vec<f64>>,
+}
+
+impl Board {
+    pub fn new(brains: Vec<Brain>, food_count: usize) -> Self {
+        let mut rng = StdRng::from_os_rng();
+        let snakes: Vec<Snake> = (0..brains.len())
+            .map(|_| {
+                let start = Pos::random(&mut rng);
+                Snake::new(start.x, start.y)
+            })
+            .collect();
+        let foods = (0..food_count).map(|_| Pos::random(&mut rng)).collect();
+        let alive = vec![true; brains.len()];
+        let death_step = vec![None; brains.len()];
+        let pheromones = vec![vec![0.0; (rows() * cols()) as usize]; snakes.len()];
+
+        Self {
+            snakes,
+            brains,
+            alive,
+            death_step,
+            foods,
+            steps: 0,
+            rng,
+            pheromones,
+        }
+    }
+
+    pub fn inside(pos: Pos) -> bool {
+        pos.x >= 0 && pos.x < cols() && pos.y >= 0 && pos.y < rows()
+    }
+
+    /// Whether `pos` is on the board and not occupied by any snake's body.
+    pub fn is_safe(&self, pos: Pos) -> bool {
+        Self::inside(pos) && !self.snakes.iter().any(|snake| snake.body.contains(&pos))
+    }
+
+    /// The neighbors of `pos` that are currently safe to move into.
+    pub fn safe_neighbors(&self, pos: Pos) -> Vec<Pos> {
+        [Dir::up(), Dir::down(), Dir::left(), Dir::right()]
+            .into_iter()
+            .map(|dir| {
+                let mut neighbor = pos;
+                neighbor += dir;
+                neighbor
+            })
+            .filter(|&neighbor| self.is_safe(neighbor))
+            .collect()
+    }
+
+    fn alive_count(&self) -> usize {
+        self.alive.iter().filter(|&&alive| alive).count()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.alive_count() == 0
+    }
+
+    /// Advances every living snake by one tick: senses, moves, resolves
+    /// collisions (wall, self, and other snakes), then resolves food.
+    pub fn update(&mut self) {
+        if self.is_over() {
+            return;
+        }
+        self.steps += 1;
+
+        let desired_directions: Vec<Dir> = (0..self.snakes.len())
+            .map(|i| {
+                if !self.alive[i] {
+                    return self.snakes[i].direction;
+                }
+                let input = self.sense(i);
+                let output = self.brains[i].make_move(&input);
+                let max_index = output
+                    .iter()
+                    .enumerate()
+                    .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                    .map(|(idx, _)| idx)
+                    .unwrap();
+                match max_index {
+                    0 => Dir::up(),
+                    1 => Dir::down(),
+                    2 => Dir::left(),
+                    3 => Dir::right(),
+                    _ => self.snakes[i].direction,
+                }
+            })
+            .collect();
+
+        for (i, snake) in self.snakes.iter_mut().enumerate() {
+            if !self.alive[i] {
+                continue;
+            }
+            if snake.can_turn(desired_directions[i]) {
+                snake.direction = desired_directions[i];
+            }
+            snake.update();
+        }
+
+        self.deposit_pheromones();
+        self.resolve_collisions();
+        self.resolve_food();
+    }
+
+    /// Deposits pheromone on each living snake's current head cell, then
+    /// decays every snake's trail. Mirrors `Game::deposit_pheromone`.
+    fn deposit_pheromones(&mut self) {
+        for i in 0..self.snakes.len() {
+            if !self.alive[i] {
+                continue;
+            }
+            let head = self.snakes[i].head();
+            if Self::inside(head) {
+                self.pheromones[i][pheromone_index(head)] += PHEROMONE_DEPOSIT;
+            }
+        }
+        for trail in &mut self.pheromones {
+            for intensity in trail.iter_mut() {
+                *intensity *= PHEROMONE_DECAY;
+            }
+        }
+    }
+
+    fn pheromone_at(&self, idx: usize, pos: Pos) -> f64 {
+        if !Self::inside(pos) {
+            return 0.0;
+        }
+        self.pheromones[idx][pheromone_index(pos)]
+    }
+
+    fn resolve_collisions(&mut self) {
+        let mut dead = vec![false; self.snakes.len()];
+
+        for i in 0..self.snakes.len() {
+            if !self.alive[i] {
+                continue;
+            }
+            let head = self.snakes[i].head();
+            if !Self::inside(head) {
+                dead[i] = true;
+                continue;
+            }
+            // Every snake spawns at length 1 and grows one segment at a
+            // time, so this range is empty (and harmless) until there's
+            // an actual body to run into. Mirrors `Game::step`.
+            for k in 2..self.snakes[i].len() {
+                if self.snakes[i].body[k] == head {
+                    dead[i] = true;
+                    break;
+                }
+            }
+        }
+
+        for i in 0..self.snakes.len() {
+            if !self.alive[i] || dead[i] {
+                continue;
+            }
+            let head_i = self.snakes[i].head();
+            for j in 0..self.snakes.len() {
+                if i == j || !self.alive[j] {
+                    continue;
+                }
+                let head_j = self.snakes[j].head();
+                if head_i == head_j {
+                    // Head-to-head: the shorter snake dies; ties kill both.
+                    match self.snakes[i].len().cmp(&self.snakes[j].len()) {
+                        std::cmp::Ordering::Less => dead[i] = true,
+                        std::cmp::Ordering::Equal => dead[i] = true,
+                        std::cmp::Ordering::Greater => {}
+                    }
+                } else if self.snakes[j].body[1..].contains(&head_i) {
+                    dead[i] = true;
+                }
+            }
+        }
+
+        for (i, is_dead) in dead.into_iter().enumerate() {
+            if is_dead {
+                self.alive[i] = false;
+                self.death_step[i] = Some(self.steps);
+            }
+        }
+    }
+
+    fn resolve_food(&mut self) {
+        for i in 0..self.snakes.len() {
+            if !self.alive[i] {
+                continue;
+            }
+            let head = self.snakes[i].head();
+            if let Some(food_index) = self.foods.iter().position(|&food| food == head) {
+                self.snakes[i].grow();
+                self.foods[food_index] = Pos::random(&mut self.rng);
+            }
+        }
+    }
+
+    /// Builds the feature vector snake `idx` feeds into its `Brain`: the
+    /// same head position / heading / nearest-food delta as the
+    /// single-player `Game::input`, plus, for each of the four look
+    /// directions, wall distance, own-body hit, and enemy-body hit, plus
+    /// the one-step-ahead pheromone reading in each direction.
+    fn sense(&self, idx: usize) -> Vec<f64> {
+        let snake = &self.snakes[idx];
+        let head = snake.head();
+        let nearest_food = self
+            .foods
+            .iter()
+            .min_by_key(|food| (food.x - head.x).abs() + (food.y - head.y).abs())
+            .copied()
+            .unwrap_or(head);
+
+        let mut features = vec![
+            head.x as f64 / cols() as f64,
+            head.y as f64 / rows() as f64,
+            snake.direction.hval() as f64,
+            snake.direction.vval() as f64,
+            (nearest_food.x - head.x) as f64 / cols() as f64,
+            (nearest_food.y - head.y) as f64 / rows() as f64,
+        ];
+
+        for dir in [Dir::up(), Dir::down(), Dir::left(), Dir::right()] {
+            let (wall_distance, own_hit, enemy_hit) = self.look_in_direction(idx, dir);
+            features.push(wall_distance);
+            features.push(own_hit);
+            features.push(enemy_hit);
+        }
+
+        for dir in [Dir::up(), Dir::down(), Dir::left(), Dir::right()] {
+            let mut ahead = head;
+            ahead += dir;
+            features.push(self.pheromone_at(idx, ahead));
+        }
+
+        features
+    }
+
+    fn look_in_direction(&self, idx: usize, direction: Dir) -> (f64, f64, f64) {
+        let mut pos = self.snakes[idx].head();
+        let mut distance = 0.0;
+        let mut own_hit = 0.0;
+        let mut enemy_hit = 0.0;
+        loop {
+            pos += direction;
+            distance += 1.0;
+            if !Self::inside(pos) {
+                break; // Hit a wall
+            }
+            if self.snakes[idx].body.contains(&pos) {
+                own_hit = 1.0;
+                break;
+            }
+            if self
+                .snakes
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != idx && other.body.contains(&pos))
+            {
+                enemy_hit = 1.0;
+                break;
+            }
+        }
+        (distance / cols() as f64, own_hit, enemy_hit)
+    }
+
+    /// Co-evolutionary fitness for snake `idx`: survival time plus food
+    /// eaten, scored against the live opponents it actually faced, with a
+    /// small bonus for ending somewhere with open space to maneuver, to
+    /// discourage coiling into dead ends. Survival time is the snake's own
+    /// death tick (or the match length if it lived to the end), not the
+    /// board's total tick count, so a snake killed early doesn't get the
+    /// same survival credit as one that outlasted it.
+    pub fn fitness(&self, idx: usize) -> f32 {
+        let survival = self.death_step[idx].unwrap_or(self.steps) as f32;
+        let food_eaten = (self.snakes[idx].len() - 1) as f32;
+        let space_bonus = self.safe_neighbors(self.snakes[idx].head()).len() as f32;
+        survival + 100.0 * food_eaten + space_bonus
+    }
+}
@@ -1,4 +1,5 @@
 use macroquad::color::*;
+use macroquad::input::{is_key_pressed, KeyCode};
 use macroquad::shapes::draw_rectangle;
 use macroquad::text::draw_text;
 use macroquad::time::get_time;
@@ -7,13 +8,36 @@ use neural_network_study::{ActivationFunction, NeuralNetwork};
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use std::ops::AddAssign;
+use std::sync::OnceLock;
 use std::vec;
 
+mod astar;
+mod board;
+mod mcts;
+mod server;
+
+use astar::AStarBrain;
+use board::Board;
+use mcts::MctsBrain;
+
 // Constants for the game
-/// Number of grid rows
-const ROWS: i32 = 20;
-/// Number of grid columns
-const COLS: i32 = 20;
+/// Default number of grid rows, overridable via `--rows`
+const DEFAULT_ROWS: i32 = 20;
+/// Default number of grid columns, overridable via `--cols`
+const DEFAULT_COLS: i32 = 20;
+
+static ROWS_OVERRIDE: OnceLock<i32> = OnceLock::new();
+static COLS_OVERRIDE: OnceLock<i32> = OnceLock::new();
+
+/// Number of grid rows for this run, set once at startup from CLI args.
+fn rows() -> i32 {
+    *ROWS_OVERRIDE.get().unwrap_or(&DEFAULT_ROWS)
+}
+
+/// Number of grid columns for this run, set once at startup from CLI args.
+fn cols() -> i32 {
+    *COLS_OVERRIDE.get().unwrap_or(&DEFAULT_COLS)
+}
 
 /// Size of the population
 const POPULATION_SIZE: usize = 250;
@@ -24,7 +48,24 @@ const MAX_STEPS: usize = 500;
 /// Probability of mutation of a gene (weight) of the neural network
 const MUTATION_RATE: f64 = 0.1;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// Number of brains competing together in a single co-evolutionary match
+const MATCH_SIZE: usize = 4;
+/// Number of randomized match groupings played per generation, so fitness
+/// isn't decided by a single draw of opponents
+const MATCHES_PER_GENERATION: usize = 3;
+/// Number of food items on a multi-snake `Board`
+const FOOD_COUNT: usize = 3;
+
+/// Amount of pheromone deposited onto a snake's current head cell each tick
+const PHEROMONE_DEPOSIT: f64 = 1.0;
+/// Fraction of pheromone remaining on every cell after each tick's decay
+const PHEROMONE_DECAY: f64 = 0.95;
+
+/// Select/expand/simulate/backpropagate iterations `MctsBrain` runs per move
+/// in `--agent mcts` mode.
+const MCTS_ITERATIONS: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 struct Pos {
     x: i32,
     y: i32,
@@ -36,12 +77,17 @@ impl Pos {
     }
 
     fn random(rng: &mut StdRng) -> Self {
-        let x = rng.random_range(0..COLS);
-        let y = rng.random_range(0..ROWS);
+        let x = rng.random_range(0..cols());
+        let y = rng.random_range(0..rows());
         Self::new(x, y)
     }
 }
 
+/// Index of `pos` into a flat `rows() * cols()` pheromone grid.
+fn pheromone_index(pos: Pos) -> usize {
+    (pos.y * cols() + pos.x) as usize
+}
+
 impl AddAssign<Dir> for Pos {
     fn add_assign(&mut self, dir: Dir) {
         match dir {
@@ -87,8 +133,19 @@ impl Dir {
             _ => 0,
         }
     }
+
+    /// The ≤3 moves reachable from `self` without reversing: continuing
+    /// straight, and the two perpendicular turns. Shared by search-based
+    /// controllers (MCTS, A*) that enumerate candidate next directions.
+    fn non_reversing_moves(&self) -> [Dir; 3] {
+        match self {
+            Self::Horizontal(_) => [*self, Dir::up(), Dir::down()],
+            Self::Vertical(_) => [*self, Dir::left(), Dir::right()],
+        }
+    }
 }
 
+#[derive(Clone)]
 struct Snake {
     body: Vec<Pos>,
     direction: Dir,
@@ -144,7 +201,7 @@ impl Snake {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum GameState {
     Running,
     Over,
@@ -157,13 +214,22 @@ struct Brain {
 
 impl Brain {
     fn new(rng: Option<&mut StdRng>) -> Self {
-        let mut nn = NeuralNetwork::new(14, 16, 4, rng);
+        // 6 fixed features + 4 look directions * (wall, own body, enemy
+        // body) + 4 one-step-ahead pheromone readings
+        let mut nn = NeuralNetwork::new(22, 16, 4, rng);
 
         nn.set_activation_function(ActivationFunction::Tanh);
 
         Self { nn }
     }
 
+    /// Wraps an already-trained network, e.g. one loaded from JSON to serve
+    /// over the Battlesnake HTTP API.
+    fn from_network(mut nn: NeuralNetwork) -> Self {
+        nn.set_activation_function(ActivationFunction::Tanh);
+        Self { nn }
+    }
+
     fn make_move(&self, input: &Vec<f64>) -> Vec<f64> {
         // Use the neural network to make a move
         self.nn.predict(input.clone());
@@ -174,6 +240,7 @@ impl Brain {
     }
 }
 
+#[derive(Clone)]
 struct Game {
     state: GameState,
     food: Pos,
@@ -181,6 +248,9 @@ struct Game {
     snake: Snake,
     brain: Brain,
     rng: StdRng,
+    /// Decaying trail of cells the snake has recently occupied, like an ant
+    /// pheromone trail, so the brain can sense "I was just here".
+    pheromones: Vec<f64>,
 }
 
 impl Game {
@@ -190,90 +260,136 @@ impl Game {
             state: GameState::Running,
             food: Pos::random(&mut rng),
             steps: 0,
-            snake: Snake::new(COLS / 2, ROWS / 2),
+            snake: Snake::new(cols() / 2, rows() / 2),
             brain,
             rng,
+            pheromones: vec![0.0; (rows() * cols()) as usize],
         }
     }
 
     fn update(&mut self) {
-        match self.state {
-            GameState::Over => {}
-            GameState::Running => {
-                self.steps += 1;
-                self.snake.update();
-                // Check for collision with itself
-                for i in 2..self.snake.len() {
-                    if self.snake.head() == self.snake.body[i] {
-                        // Game over
-                        self.state = GameState::Over;
-                        break;
-                    }
-                }
+        if self.state == GameState::Over {
+            return;
+        }
 
-                // Get the next move from the brain
-                let input = self.input();
-                let output = self.brain.make_move(&input);
-                // Update the snake's direction based on the brain's output
-                let max_index = output
-                    .iter()
-                    .enumerate()
-                    .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
-                    .map(|(idx, _)| idx)
-                    .unwrap();
-                let desired_direction = match max_index {
-                    0 => Dir::up(),
-                    1 => Dir::down(),
-                    2 => Dir::left(),
-                    3 => Dir::right(),
-                    _ => self.snake.direction,
-                };
-                if self.snake.can_turn(desired_direction) {
-                    self.snake.direction = desired_direction;
-                }
+        // Get the next move from the brain
+        let input = self.input();
+        let output = self.brain.make_move(&input);
+        // Pick the direction the brain favors most
+        let max_index = output
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(idx, _)| idx)
+            .unwrap();
+        let desired_direction = match max_index {
+            0 => Dir::up(),
+            1 => Dir::down(),
+            2 => Dir::left(),
+            3 => Dir::right(),
+            _ => self.snake.direction,
+        };
+
+        self.step(desired_direction);
+    }
 
-                // Check for collision with food
-                if self.snake.eat(self.food) {
-                    self.food = Pos::random(&mut self.rng);
-                }
+    /// Advances the game by one tick using `desired_direction`, without
+    /// consulting a `Brain`. This is the deterministic core shared by
+    /// `update()`, by `MctsBrain`, which plans over cloned `Game` states, and
+    /// by the play loops that apply a human's or an agent's chosen move.
+    fn step(&mut self, desired_direction: Dir) {
+        if self.state == GameState::Over {
+            return;
+        }
 
-                // Check for collision with walls
-                if self.snake.head().x < 0
-                    || self.snake.head().x >= COLS
-                    || self.snake.head().y < 0
-                    || self.snake.head().y >= ROWS
-                {
-                    // Game over
-                    self.state = GameState::Over;
-                }
+        self.steps += 1;
+
+        if self.snake.can_turn(desired_direction) {
+            self.snake.direction = desired_direction;
+        }
+        self.snake.update();
+        self.deposit_pheromone();
+
+        // Check for collision with itself
+        for i in 2..self.snake.len() {
+            if self.snake.head() == self.snake.body[i] {
+                // Game over
+                self.state = GameState::Over;
+                return;
             }
         }
+
+        // Check for collision with walls
+        if self.snake.head().x < 0
+            || self.snake.head().x >= cols()
+            || self.snake.head().y < 0
+            || self.snake.head().y >= rows()
+        {
+            // Game over
+            self.state = GameState::Over;
+            return;
+        }
+
+        // Check for collision with food
+        if self.snake.eat(self.food) {
+            self.food = Pos::random(&mut self.rng);
+        }
     }
 
     fn input(&self) -> Vec<f64> {
         let head = self.snake.head();
-        vec![
+        let mut features = vec![
             // snake head x position
-            head.x as f64 / COLS as f64,
+            head.x as f64 / cols() as f64,
             // snake head y position
-            head.y as f64 / ROWS as f64,
+            head.y as f64 / rows() as f64,
             // snake horizontal speed
             self.snake.direction.hval() as f64,
             // snake vertical speed
             self.snake.direction.vval() as f64,
             // horizontal distance from food
-            (self.food.x - head.x) as f64 / COLS as f64,
+            (self.food.x - head.x) as f64 / cols() as f64,
             // vertical distance from food
-            (self.food.y - head.y) as f64 / ROWS as f64,
-            self.look_in_direction(Dir::up()).0, // Wall distance up
-            self.look_in_direction(Dir::up()).1, // Body hit up
-            self.look_in_direction(Dir::down()).0,
-            self.look_in_direction(Dir::down()).1,
-            self.look_in_direction(Dir::left()).0,
-            self.look_in_direction(Dir::left()).1,
-            self.look_in_direction(Dir::right()).0,
-            self.look_in_direction(Dir::right()).1,
-        ]
+            (self.food.y - head.y) as f64 / rows() as f64,
+        ];
+        // Per direction: wall distance, own-body hit, enemy-body hit. A
+        // solo `Game` has no other snakes, so enemy hit is always 0 here;
+        // `Board::sense` fills it in for real in competitive matches, and
+        // both feed the same `Brain` shape.
+        for dir in [Dir::up(), Dir::down(), Dir::left(), Dir::right()] {
+            let (wall_distance, body_hit, enemy_hit) = self.look_in_direction(dir);
+            features.push(wall_distance);
+            features.push(body_hit);
+            features.push(enemy_hit);
+        }
+        // Pheromone intensity one step ahead in each direction, so the
+        // brain can sense cells it was recently in.
+        for dir in [Dir::up(), Dir::down(), Dir::left(), Dir::right()] {
+            let mut ahead = head;
+            ahead += dir;
+            features.push(self.pheromone_at(ahead));
+        }
+        features
+    }
+
+    /// Deposits pheromone on the current head cell (if it's still on the
+    /// board), then decays the whole grid. Called once per tick from
+    /// `step`, before the wall-collision check.
+    fn deposit_pheromone(&mut self) {
+        let head = self.snake.head();
+        if head.x >= 0 && head.x < cols() && head.y >= 0 && head.y < rows() {
+            self.pheromones[pheromone_index(head)] += PHEROMONE_DEPOSIT;
+        }
+        for intensity in &mut self.pheromones {
+            *intensity *= PHEROMONE_DECAY;
+        }
+    }
+
+    fn pheromone_at(&self, pos: Pos) -> f64 {
+        if pos.x < 0 || pos.x >= cols() || pos.y < 0 || pos.y >= rows() {
+            return 0.0;
+        }
+        self.pheromones[pheromone_index(pos)]
     }
 
     fn evaluate(&self) -> f32 {
@@ -286,14 +402,14 @@ impl Game {
         }
     }
 
-    fn look_in_direction(&self, direction: Dir) -> (f64, f64) {
+    fn look_in_direction(&self, direction: Dir) -> (f64, f64, f64) {
         let mut pos = self.snake.head();
         let mut distance = 0.0;
         let mut body_hit = 0.0;
         loop {
             pos += direction;
             distance += 1.0;
-            if pos.x < 0 || pos.x >= COLS || pos.y < 0 || pos.y >= ROWS {
+            if pos.x < 0 || pos.x >= cols() || pos.y < 0 || pos.y >= rows() {
                 break; // Hit a wall
             }
             if self.snake.body.contains(&pos) {
@@ -301,17 +417,17 @@ impl Game {
                 break;
             }
         }
-        (distance / COLS as f64, body_hit) // Normalized distance, body hit indicator
+        // Normalized distance, body hit indicator, enemy hit indicator
+        (distance / cols() as f64, body_hit, 0.0)
     }
 }
 
 fn train() -> Option<Brain> {
     let mut rng = StdRng::from_os_rng();
     let mut generation = 0;
-    let mut population = vec![];
-    for _ in 0..POPULATION_SIZE {
-        population.push(Game::new(Brain::new(Some(&mut rng))));
-    }
+    let mut population: Vec<Brain> = (0..POPULATION_SIZE)
+        .map(|_| Brain::new(Some(&mut rng)))
+        .collect();
     let mut champion = None;
 
     loop {
@@ -322,45 +438,45 @@ fn train() -> Option<Brain> {
             break;
         }
 
-        // Run the simulation with the current population
-        // until all games are over
-        let mut steps = 0;
-        loop {
-            steps += 1;
-            if steps > MAX_STEPS {
-                break;
-            }
+        // Co-evolutionary fitness: play several randomized groupings of
+        // MATCH_SIZE brains against each other on a shared Board, and
+        // average each brain's fitness across the matches it took part in.
+        let mut fitness = vec![0.0f32; POPULATION_SIZE];
+        for _ in 0..MATCHES_PER_GENERATION {
+            let mut order: Vec<usize> = (0..POPULATION_SIZE).collect();
+            order.shuffle(&mut rng);
+
+            for group in order.chunks(MATCH_SIZE) {
+                let brains = group.iter().map(|&i| population[i].clone()).collect();
+                let mut board = Board::new(brains, FOOD_COUNT);
+
+                let mut steps = 0;
+                while !board.is_over() && steps < MAX_STEPS {
+                    board.update();
+                    steps += 1;
+                }
 
-            let mut alive = false;
-            for game in &mut population
-                .iter_mut()
-                .filter(|g| g.state == GameState::Running)
-            {
-                alive = true;
-                game.update();
-            }
-            if !alive {
-                break;
+                for (slot, &i) in group.iter().enumerate() {
+                    fitness[i] += board.fitness(slot);
+                }
             }
         }
+        for score in &mut fitness {
+            *score /= MATCHES_PER_GENERATION as f32;
+        }
 
         // Evaluate the population and create a mating pool
-        let mut mating_pool = vec![];
-        let mut scored_games = population
-            .iter()
-            .map(|g| (g.evaluate(), g))
-            .collect::<Vec<_>>();
-        scored_games.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        let mut scored_brains = fitness.into_iter().zip(population.iter()).collect::<Vec<_>>();
+        scored_brains.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
         let mut score_sum = 0.0;
         let mut best_score = 0.0;
-        for (score, game) in &scored_games {
+        for (score, brain) in &scored_brains {
             assert!(score >= &0.0);
             score_sum += score;
             if score > &best_score {
                 best_score = *score;
-                champion = Some(game.brain.clone());
+                champion = Some((*brain).clone());
             }
-            mating_pool.push(game);
         }
 
         println!("Best score: {}", best_score);
@@ -368,32 +484,32 @@ fn train() -> Option<Brain> {
 
         // Create a new generation
         let mut new_population = vec![];
-        let len = scored_games.len();
+        let len = scored_brains.len();
         while new_population.len() < POPULATION_SIZE {
             // Randomly select a parent from the mating pool
             let selected_parent = if score_sum > 0.0 {
                 let r = rng.random_range(0.0..score_sum);
                 let mut cumulative_score = 0.0;
                 let mut selected_parent = None;
-                for (score, game) in &scored_games {
+                for (score, brain) in &scored_brains {
                     cumulative_score += score;
                     if cumulative_score >= r {
-                        selected_parent = Some(game);
+                        selected_parent = Some(*brain);
                         break;
                     }
                 }
                 selected_parent
             } else if score_sum == 0.0 {
                 let i = rng.random_range(0..len);
-                Some(&scored_games[i].1)
+                Some(scored_brains[i].1)
             } else {
                 panic!();
             };
             let selected_parent = selected_parent.unwrap();
             // Apply some mutation to the parent's brain
-            let mut child_brain = selected_parent.brain.clone();
+            let mut child_brain = selected_parent.clone();
             child_brain.mutate(&mut rng, MUTATION_RATE);
-            new_population.push(Game::new(child_brain));
+            new_population.push(child_brain);
         }
         population = new_population;
     }
@@ -412,42 +528,243 @@ fn train() -> Option<Brain> {
 
 const W: f32 = 20.0;
 
+/// Parsed command-line arguments. `serve`/`play`/`agent` are mutually
+/// exclusive modes; when none is set, `main` trains and then auto-plays.
+struct Args {
+    serve: Option<(String, u16)>,
+    play: bool,
+    /// `--agent mcts|astar` drives the game with `MctsBrain`/`AStarBrain`
+    /// instead of a trained `Brain`, for comparing the evolved policy
+    /// against search-based baselines.
+    agent: Option<String>,
+    rows: i32,
+    cols: i32,
+    update_time: f64,
+}
+
+fn parse_args() -> Args {
+    let raw: Vec<String> = std::env::args().collect();
+    let mut args = Args {
+        serve: None,
+        play: false,
+        agent: None,
+        rows: DEFAULT_ROWS,
+        cols: DEFAULT_COLS,
+        update_time: 0.5,
+    };
+
+    let mut i = 1;
+    while i < raw.len() {
+        match raw[i].as_str() {
+            "serve" => {
+                let champion_path = raw
+                    .get(i + 1)
+                    .expect("usage: snake serve <champion.json> [port]")
+                    .clone();
+                let port = raw.get(i + 2).and_then(|arg| arg.parse().ok()).unwrap_or(8000);
+                args.serve = Some((champion_path, port));
+                i += 3;
+            }
+            "--play" => {
+                args.play = true;
+                i += 1;
+            }
+            "--agent" => {
+                args.agent = Some(raw.get(i + 1).expect("usage: snake --agent mcts|astar").clone());
+                i += 2;
+            }
+            "--rows" => {
+                args.rows = raw.get(i + 1).and_then(|arg| arg.parse().ok()).unwrap_or(DEFAULT_ROWS);
+                i += 2;
+            }
+            "--cols" => {
+                args.cols = raw.get(i + 1).and_then(|arg| arg.parse().ok()).unwrap_or(DEFAULT_COLS);
+                i += 2;
+            }
+            "--speed" => {
+                args.update_time = raw.get(i + 1).and_then(|arg| arg.parse().ok()).unwrap_or(0.5);
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+
+    args
+}
+
+fn pressed_direction() -> Option<Dir> {
+    if is_key_pressed(KeyCode::Up) {
+        Some(Dir::up())
+    } else if is_key_pressed(KeyCode::Down) {
+        Some(Dir::down())
+    } else if is_key_pressed(KeyCode::Left) {
+        Some(Dir::left())
+    } else if is_key_pressed(KeyCode::Right) {
+        Some(Dir::right())
+    } else {
+        None
+    }
+}
+
+fn draw_game(game: &Game) {
+    clear_background(BLACK);
+
+    // Draw the grid
+    draw_rectangle(0.0, 0.0, cols() as f32 * W, rows() as f32 * W, WHITE);
+
+    // Draw the snake
+    for segment in &game.snake.body {
+        draw_rectangle(segment.x as f32 * W, segment.y as f32 * W, W, W, BLACK);
+    }
+    // Draw the food
+    draw_rectangle(game.food.x as f32 * W, game.food.y as f32 * W, W, W, GREEN);
+
+    if game.state != GameState::Running {
+        draw_text("Game Over - press R to restart", 10.0, W, W, BLACK);
+    } else {
+        draw_text("Running", 10.0, W, W, BLACK);
+    }
+    draw_text(&format!("Score: {}", game.evaluate()), 10.0, 40.0, W, BLACK);
+}
+
+/// Lets a person drive the snake with the arrow keys instead of the
+/// evolved `Brain`, for benchmarking a trained champion head-to-head
+/// against a human on the same board.
+async fn play_human(update_time: f64) {
+    let mut game = Game::new(Brain::new(None));
+    let mut direction = Dir::right();
+    let mut last_update = get_time();
+
+    loop {
+        if let Some(new_direction) = pressed_direction() {
+            direction = new_direction;
+        }
+
+        if game.state == GameState::Running && get_time() - last_update > update_time {
+            game.step(direction);
+            last_update = get_time();
+        }
+
+        if game.state != GameState::Running && is_key_pressed(KeyCode::R) {
+            game = Game::new(Brain::new(None));
+            direction = Dir::right();
+            last_update = get_time();
+        }
+
+        draw_game(&game);
+        next_frame().await;
+    }
+}
+
+/// Drives a `Game` with `MctsBrain` instead of a trained `Brain`, for
+/// `--agent mcts`, so the search-based baseline is actually watchable and
+/// comparable to the evolved policy instead of being dead code.
+async fn play_mcts(update_time: f64) {
+    let mcts_brain = MctsBrain::new(MCTS_ITERATIONS);
+    let mut rng = StdRng::from_os_rng();
+    let mut game = Game::new(Brain::new(Some(&mut rng)));
+    let mut last_update = get_time();
+
+    loop {
+        if game.state == GameState::Running && get_time() - last_update > update_time {
+            let direction = mcts_brain.decide(&game, &mut rng);
+            game.step(direction);
+            last_update = get_time();
+        }
+
+        if game.state != GameState::Running && is_key_pressed(KeyCode::R) {
+            game = Game::new(Brain::new(Some(&mut rng)));
+            last_update = get_time();
+        }
+
+        draw_game(&game);
+        next_frame().await;
+    }
+}
+
+/// Drives a `Game` with `AStarBrain` instead of a trained `Brain`, for
+/// `--agent astar`, so the scripted pathfinding opponent is actually
+/// watchable and usable as a fitness yardstick instead of dead code.
+async fn play_astar(update_time: f64) {
+    let astar_brain = AStarBrain::new();
+    let mut rng = StdRng::from_os_rng();
+    let mut game = Game::new(Brain::new(Some(&mut rng)));
+    let mut last_update = get_time();
+
+    loop {
+        if game.state == GameState::Running && get_time() - last_update > update_time {
+            let direction = astar_brain.decide(&game.snake, game.food);
+            game.step(direction);
+            last_update = get_time();
+        }
+
+        if game.state != GameState::Running && is_key_pressed(KeyCode::R) {
+            game = Game::new(Brain::new(Some(&mut rng)));
+            last_update = get_time();
+        }
+
+        draw_game(&game);
+        next_frame().await;
+    }
+}
+
 #[macroquad::main("Snake")]
 async fn main() {
+    let args = parse_args();
+    let _ = ROWS_OVERRIDE.set(args.rows);
+    let _ = COLS_OVERRIDE.set(args.cols);
+
+    // `snake serve <champion.json> [port]` runs the Battlesnake HTTP API
+    // instead of training/drawing a window.
+    if let Some((champion_path, port)) = args.serve {
+        let champion_json =
+            std::fs::read_to_string(&champion_path).expect("failed to read champion file");
+        let nn: NeuralNetwork =
+            serde_json::from_str(&champion_json).expect("failed to parse champion JSON");
+        server::run(Brain::from_network(nn), port);
+        return;
+    }
+
+    // `snake --play` hands control to a human instead of training.
+    if args.play {
+        play_human(args.update_time).await;
+        return;
+    }
+
+    // `snake --agent mcts|astar` watches a search-based controller instead
+    // of a trained `Brain`.
+    match args.agent.as_deref() {
+        Some("mcts") => {
+            play_mcts(args.update_time).await;
+            return;
+        }
+        Some("astar") => {
+            play_astar(args.update_time).await;
+            return;
+        }
+        Some(other) => panic!("unknown --agent {other}, expected mcts or astar"),
+        None => {}
+    }
+
     // Train the neural network
     let brain = train().expect("Failed to train the neural network");
     // Create a new game with the trained brain
     let mut game = Game::new(brain);
     // Run the game until it's over
-    let update_time = 0.5;
     let mut last_update = get_time();
     loop {
         // Update the game at a fixed interval
-        if get_time() - last_update > update_time {
+        if get_time() - last_update > args.update_time {
             game.update();
             last_update = get_time();
         }
 
-        clear_background(BLACK);
-
-        // Draw the grid
-        draw_rectangle(0.0, 0.0, COLS as f32 * W, ROWS as f32 * W, WHITE);
-
-        // Draw the snake
-        for segment in &game.snake.body {
-            draw_rectangle(segment.x as f32 * W, segment.y as f32 * W, W, W, BLACK);
-        }
-        // Draw the food
-        draw_rectangle(game.food.x as f32 * W, game.food.y as f32 * W, W, W, GREEN);
-
-        // Update the game state
-        if game.state != GameState::Running {
-            draw_text("Game Over", 10.0, W, W, BLACK);
-        } else {
-            draw_text("Running", 10.0, W, W, BLACK);
+        if game.state != GameState::Running && is_key_pressed(KeyCode::R) {
+            game = Game::new(game.brain.clone());
+            last_update = get_time();
         }
-        draw_text(&format!("Score: {}", game.evaluate()), 10.0, 40.0, W, BLACK);
 
+        draw_game(&game);
         next_frame().await;
     }
 }
@@ -0,0 +1,144 @@
+//! Monte Carlo Tree Search controller, used as a search-based baseline to
+//! compare against the evolved [`Brain`](crate::Brain).
+
+use crate::{Dir, Game, GameState, MAX_STEPS};
+use rand::Rng;
+use rand::rngs::StdRng;
+
+/// Exploration constant `c` in the UCB1 formula `Q/N + c*sqrt(ln(N_parent)/N_child)`.
+const EXPLORATION: f64 = 1.4;
+
+struct Node {
+    game: Game,
+    action: Dir,
+    children: Vec<Node>,
+    untried: Vec<Dir>,
+    visits: u32,
+    total_reward: f64,
+}
+
+impl Node {
+    fn new(game: Game, action: Dir) -> Self {
+        let untried = if game.state == GameState::Over {
+            vec![]
+        } else {
+            game.snake.direction.non_reversing_moves().to_vec()
+        };
+        Self {
+            game,
+            action,
+            children: vec![],
+            untried,
+            visits: 0,
+            total_reward: 0.0,
+        }
+    }
+
+    /// UCB1 score of this node from the perspective of its parent.
+    fn ucb1(&self, parent_visits: u32) -> f64 {
+        if self.visits == 0 {
+            return f64::INFINITY;
+        }
+        self.total_reward / self.visits as f64
+            + EXPLORATION * ((parent_visits as f64).ln() / self.visits as f64).sqrt()
+    }
+
+    fn best_child_index(&self) -> usize {
+        self.children
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                a.ucb1(self.visits).partial_cmp(&b.ucb1(self.visits)).unwrap()
+            })
+            .map(|(idx, _)| idx)
+            .unwrap()
+    }
+
+    fn most_visited_action(&self) -> Dir {
+        self.children
+            .iter()
+            .max_by_key(|child| child.visits)
+            .map(|child| child.action)
+            .unwrap_or(self.game.snake.direction)
+    }
+}
+
+/// Picks the snake's next direction by Monte Carlo Tree Search over cloned
+/// `Game` states, instead of a neural network forward pass.
+pub struct MctsBrain {
+    /// Number of select/expand/simulate/backpropagate iterations per move.
+    iterations: usize,
+    /// Rollout length cap, mirroring the training loop's `MAX_STEPS`.
+    max_rollout_steps: usize,
+}
+
+impl MctsBrain {
+    pub fn new(iterations: usize) -> Self {
+        Self {
+            iterations,
+            max_rollout_steps: MAX_STEPS,
+        }
+    }
+
+    /// Runs the search from `game`'s current state and returns the root
+    /// child with the highest visit count.
+    pub fn decide(&self, game: &Game, rng: &mut StdRng) -> Dir {
+        let mut root = Node::new(game.clone(), game.snake.direction);
+        if root.untried.is_empty() {
+            return game.snake.direction;
+        }
+
+        for _ in 0..self.iterations {
+            self.visit(&mut root, rng);
+        }
+
+        root.most_visited_action()
+    }
+
+    /// Selects down to an expandable or terminal node, expands it if
+    /// possible, rolls out, and backpropagates the reward up the path.
+    fn visit(&self, node: &mut Node, rng: &mut StdRng) -> f64 {
+        if node.game.state == GameState::Over {
+            // Terminal states are zero-reward leaves.
+            node.visits += 1;
+            return 0.0;
+        }
+
+        let reward = if let Some(action) = node.untried.pop() {
+            // Expansion: add one untried move as a child and roll it out.
+            let mut child_game = node.game.clone();
+            child_game.step(action);
+            let reward = if child_game.state == GameState::Over {
+                0.0
+            } else {
+                self.rollout(child_game.clone(), rng)
+            };
+            let mut child = Node::new(child_game, action);
+            child.visits = 1;
+            child.total_reward = reward;
+            node.children.push(child);
+            reward
+        } else {
+            // Selection: descend by UCB1.
+            let idx = node.best_child_index();
+            self.visit(&mut node.children[idx], rng)
+        };
+
+        node.visits += 1;
+        node.total_reward += reward;
+        reward
+    }
+
+    /// Plays `game` forward with random non-reversing moves until it ends
+    /// or `max_rollout_steps` is hit, then scores it with `evaluate()`.
+    fn rollout(&self, mut game: Game, rng: &mut StdRng) -> f64 {
+        let mut steps = 0;
+        while game.state == GameState::Running && steps < self.max_rollout_steps {
+            let moves = game.snake.direction.non_reversing_moves();
+            let dir = moves[rng.random_range(0..moves.len())];
+            game.step(dir);
+            steps += 1;
+        }
+        game.evaluate() as f64
+    }
+}
@@ -0,0 +1,234 @@
+//! Battlesnake HTTP API server, letting a trained `Brain` play on a live
+//! board instead of only in the macroquad window.
+//!
+//! Implements the standard endpoints: `GET /`, `POST /start`, `POST /move`,
+//! and `POST /end`. See <https://docs.battlesnake.com/api>.
+
+use crate::{Brain, Dir, Pos};
+use serde::Deserialize;
+use std::io::Read;
+use tiny_http::{Method, Response, Server};
+
+#[derive(Deserialize)]
+struct Coord {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Deserialize)]
+struct BattlesnakeBody {
+    id: String,
+    head: Coord,
+    body: Vec<Coord>,
+}
+
+#[derive(Deserialize)]
+struct BoardState {
+    width: i32,
+    height: i32,
+    food: Vec<Coord>,
+    snakes: Vec<BattlesnakeBody>,
+}
+
+#[derive(Deserialize)]
+struct MoveRequest {
+    board: BoardState,
+    you: BattlesnakeBody,
+}
+
+/// Starts the Battlesnake server, blocking the calling thread to serve
+/// requests with `brain` until the process exits.
+pub fn run(brain: Brain, port: u16) {
+    let server = Server::http(("0.0.0.0", port)).expect("failed to bind Battlesnake server");
+    println!("Battlesnake server listening on :{}", port);
+
+    for mut request in server.incoming_requests() {
+        let (status, body) = match (request.method(), request.url()) {
+            (Method::Get, "/") => (200, info_response()),
+            (Method::Post, "/start") => (200, "{}".to_string()),
+            (Method::Post, "/move") => {
+                let mut payload = String::new();
+                if request.as_reader().read_to_string(&mut payload).is_err() {
+                    (400, "{}".to_string())
+                } else {
+                    move_response(&brain, &payload)
+                }
+            }
+            (Method::Post, "/end") => (200, "{}".to_string()),
+            _ => (404, "{}".to_string()),
+        };
+
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(tiny_http::Header::from_bytes(
+                &b"Content-Type"[..],
+                &b"application/json"[..],
+            )
+            .unwrap());
+        let _ = request.respond(response);
+    }
+}
+
+fn info_response() -> String {
+    r#"{"apiversion":"1","author":"brend","color":"#00ff00","head":"default","tail":"default"}"#
+        .to_string()
+}
+
+fn move_response(brain: &Brain, payload: &str) -> (u16, String) {
+    let request: MoveRequest = match serde_json::from_str(payload) {
+        Ok(request) => request,
+        Err(_) => return (400, "{}".to_string()),
+    };
+
+    // `Dir::up()`/`Dir::down()` move through our y-down grid frame; since
+    // `to_pos` flips Battlesnake's y-up coordinates into that same frame
+    // before the brain ever sees them, the labels line up directly here
+    // with no further flip needed.
+    let dir = decide_move(brain, &request);
+    let mv = match dir {
+        Dir::Vertical(v) if v < 0 => "up",
+        Dir::Vertical(_) => "down",
+        Dir::Horizontal(h) if h < 0 => "left",
+        Dir::Horizontal(_) => "right",
+    };
+
+    (200, format!(r#"{{"move":"{}"}}"#, mv))
+}
+
+fn decide_move(brain: &Brain, request: &MoveRequest) -> Dir {
+    let input = build_input(request);
+    let output = brain.make_move(&input);
+    let max_index = output
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .map(|(idx, _)| idx)
+        .unwrap();
+    match max_index {
+        0 => Dir::up(),
+        1 => Dir::down(),
+        2 => Dir::left(),
+        _ => Dir::right(),
+    }
+}
+
+/// Battlesnake's y axis increases upward; our grid's increases downward
+/// (matching `Game`/`Board`, where row 0 is drawn at the top). Every
+/// incoming coordinate is flipped through this once at the API boundary,
+/// so everything downstream — features, heading inference, and the
+/// brain's output direction — operates in one consistent frame.
+fn to_pos(coord: &Coord, height: i32) -> Pos {
+    Pos::new(coord.x, height - 1 - coord.y)
+}
+
+/// Translates a Battlesnake `/move` request into the same feature vector
+/// `Game::input`/`Board::sense` build: normalized head position, current
+/// heading, normalized food delta, per-direction wall distance / body-hit
+/// extended to enemy bodies, and a pheromone reading per direction. The
+/// live API gives us no history of the snake's own past positions, so the
+/// pheromone slots are fed as neutral zeros here.
+fn build_input(request: &MoveRequest) -> Vec<f64> {
+    let width = request.board.width;
+    let height = request.board.height;
+    let head = to_pos(&request.you.head, height);
+    let own_body: Vec<Pos> = request
+        .you
+        .body
+        .iter()
+        .map(|c| to_pos(c, height))
+        .collect();
+    let direction = infer_direction(&own_body);
+    let enemy_body: Vec<Pos> = request
+        .board
+        .snakes
+        .iter()
+        .filter(|snake| snake.id != request.you.id)
+        .flat_map(|snake| snake.body.iter().map(|c| to_pos(c, height)))
+        .collect();
+
+    let nearest_food = request
+        .board
+        .food
+        .iter()
+        .map(|c| to_pos(c, height))
+        .min_by_key(|food| (food.x - head.x).abs() + (food.y - head.y).abs());
+    let (food_dx, food_dy) = match nearest_food {
+        Some(food) => (
+            (food.x - head.x) as f64 / width as f64,
+            (food.y - head.y) as f64 / height as f64,
+        ),
+        None => (0.0, 0.0),
+    };
+
+    let mut features = vec![
+        head.x as f64 / width as f64,
+        head.y as f64 / height as f64,
+        direction.hval() as f64,
+        direction.vval() as f64,
+        food_dx,
+        food_dy,
+    ];
+
+    for dir in [Dir::up(), Dir::down(), Dir::left(), Dir::right()] {
+        let (wall_distance, own_hit, enemy_hit) =
+            look_in_direction(head, dir, width, height, &own_body, &enemy_body);
+        features.push(wall_distance);
+        features.push(own_hit);
+        features.push(enemy_hit);
+    }
+
+    // No pheromone history is available over the API.
+    features.extend([0.0; 4]);
+
+    features
+}
+
+fn look_in_direction(
+    head: Pos,
+    direction: Dir,
+    width: i32,
+    height: i32,
+    own_body: &[Pos],
+    enemy_body: &[Pos],
+) -> (f64, f64, f64) {
+    let mut pos = head;
+    let mut distance = 0.0;
+    let mut own_hit = 0.0;
+    let mut enemy_hit = 0.0;
+    loop {
+        pos += direction;
+        distance += 1.0;
+        if pos.x < 0 || pos.x >= width || pos.y < 0 || pos.y >= height {
+            break; // Hit a wall
+        }
+        if own_body.contains(&pos) {
+            own_hit = 1.0;
+            break;
+        }
+        if enemy_body.contains(&pos) {
+            enemy_hit = 1.0;
+            break;
+        }
+    }
+    (distance / width as f64, own_hit, enemy_hit)
+}
+
+/// Battlesnake doesn't report a snake's heading directly, so it's inferred
+/// from the first two body segments (head and neck), already converted to
+/// our grid's y-down frame by `to_pos`.
+fn infer_direction(body: &[Pos]) -> Dir {
+    let [head, neck, ..] = body else {
+        return Dir::right();
+    };
+    if head.x != neck.x {
+        if head.x > neck.x {
+            Dir::right()
+        } else {
+            Dir::left()
+        }
+    } else if head.y < neck.y {
+        Dir::up()
+    } else {
+        Dir::down()
+    }
+}